@@ -1,17 +1,119 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     env, fmt, fs,
     hash::{DefaultHasher, Hash, Hasher},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
+use geo::{LineString, Polygon};
 use geojson::{Feature, FeatureCollection, JsonObject, JsonValue};
-use h3o::{geom::ToGeo, CellIndex, DirectedEdgeIndex, LatLng};
+use h3o::{
+    geom::{ContainmentMode, Geometry, PolyfillConfig, ToCells, ToGeo},
+    CellIndex, DirectedEdgeIndex, LatLng, Resolution,
+};
 
 pub struct H3oViewer {
     cell_groups: Vec<Vec<CellIndex>>,
     settings: Settings,
     circles: Vec<(LatLng, usize)>,
+    bounding_boxes: Vec<BBox>,
+    tile_grid_zoom: Option<u8>,
+    cell_values: HashMap<CellIndex, f64>,
+    color_scale: ColorScale,
+    group_colors: Option<Vec<String>>,
+}
+
+/// Palette cycled through by [`H3oViewer::for_cell_groups`] to give each
+/// group a distinct color, unless overridden with
+/// [`H3oViewer::with_group_colors`].
+const DEFAULT_GROUP_PALETTE: [&str; 8] = [
+    "#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4", "#46f0f0", "#f032e6", "#bcf60c",
+];
+
+/// Color scale used by [`H3oViewer::for_cell_values`] to map a cell's value
+/// to a fill color.
+#[derive(Debug, Clone)]
+pub enum ColorScale {
+    /// Linear interpolation between two `#rrggbb` colors.
+    Linear(String, String),
+    /// Perceptually-uniform dark-purple to yellow ramp.
+    Viridis,
+    /// Light-to-dark blue ramp.
+    Blues,
+}
+
+impl Default for ColorScale {
+    fn default() -> Self {
+        Self::Viridis
+    }
+}
+
+impl ColorScale {
+    fn anchors(&self) -> Vec<(f64, f64, f64)> {
+        match self {
+            Self::Linear(start, end) => vec![hex_to_rgb(start), hex_to_rgb(end)],
+            Self::Viridis => vec![
+                hex_to_rgb("#440154"),
+                hex_to_rgb("#31688e"),
+                hex_to_rgb("#35b779"),
+                hex_to_rgb("#fde725"),
+            ],
+            Self::Blues => vec![hex_to_rgb("#f7fbff"), hex_to_rgb("#08306b")],
+        }
+    }
+
+    /// Maps `t` (clamped to `[0.0, 1.0]`) to a `#rrggbb` color.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn color_at(&self, t: f64) -> String {
+        let anchors = self.anchors();
+        let segments = anchors.len() - 1;
+        let scaled = t.clamp(0.0, 1.0) * segments as f64;
+        let index = (scaled.floor() as usize).min(segments - 1);
+        let local_t = scaled - index as f64;
+
+        let (r0, g0, b0) = anchors[index];
+        let (r1, g1, b1) = anchors[index + 1];
+        let lerp = |a: f64, b: f64| a + (b - a) * local_t;
+
+        rgb_to_hex(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1))
+    }
+}
+
+fn hex_to_rgb(hex: &str) -> (f64, f64, f64) {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return (0.0, 0.0, 0.0);
+    }
+
+    let channel = |range: std::ops::Range<usize>| {
+        hex.get(range)
+            .and_then(|s| u8::from_str_radix(s, 16).ok())
+            .unwrap_or(0)
+    };
+    (
+        f64::from(channel(0..2)),
+        f64::from(channel(2..4)),
+        f64::from(channel(4..6)),
+    )
+}
+
+fn rgb_to_hex(r: f64, g: f64, b: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (r, g, b) = (r.round() as u8, g.round() as u8, b.round() as u8);
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// A rectangular lat/lng extent, in degrees.
+#[derive(Debug, Clone, Copy)]
+pub struct BBox {
+    pub north: f64,
+    pub south: f64,
+    pub east: f64,
+    pub west: f64,
 }
 
 #[derive(Debug)]
@@ -32,6 +134,11 @@ impl fmt::Debug for H3oViewer {
             .field("cells", &"Iterator over CellIndexes")
             .field("settings", &self.settings)
             .field("circles", &self.circles)
+            .field("bounding_boxes", &self.bounding_boxes)
+            .field("tile_grid_zoom", &self.tile_grid_zoom)
+            .field("cell_values", &self.cell_values)
+            .field("color_scale", &self.color_scale)
+            .field("group_colors", &self.group_colors)
             .finish()
     }
 }
@@ -41,6 +148,11 @@ impl Hash for H3oViewer {
         format!("{:?}", self.cell_groups).hash(state);
         format!("{:?}", self.settings).hash(state);
         format!("{:?}", self.circles).hash(state);
+        format!("{:?}", self.bounding_boxes).hash(state);
+        self.tile_grid_zoom.hash(state);
+        format!("{:?}", self.cell_values).hash(state);
+        format!("{:?}", self.color_scale).hash(state);
+        format!("{:?}", self.group_colors).hash(state);
     }
 }
 
@@ -61,6 +173,11 @@ impl H3oViewer {
             cell_groups: Vec::from([cells.into_iter().collect()]),
             settings: Settings::default(),
             circles: Vec::new(),
+            bounding_boxes: Vec::new(),
+            tile_grid_zoom: None,
+            cell_values: HashMap::new(),
+            color_scale: ColorScale::default(),
+            group_colors: None,
         }
     }
 
@@ -74,9 +191,79 @@ impl H3oViewer {
                 .collect(),
             settings: Settings::default(),
             circles: Vec::new(),
+            bounding_boxes: Vec::new(),
+            tile_grid_zoom: None,
+            cell_values: HashMap::new(),
+            color_scale: ColorScale::default(),
+            group_colors: None,
+        }
+    }
+
+    /// Fills the given bounding box with every cell of `resolution` covering
+    /// it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounding box cannot be turned into a valid polygon.
+    #[must_use]
+    pub fn for_bounding_box(bbox: BBox, resolution: Resolution) -> Self {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (bbox.west, bbox.south),
+                (bbox.east, bbox.south),
+                (bbox.east, bbox.north),
+                (bbox.west, bbox.north),
+                (bbox.west, bbox.south),
+            ]),
+            Vec::new(),
+        );
+        let geometry =
+            Geometry::from_degrees(polygon).expect("bounding box should be a valid polygon");
+        let config =
+            PolyfillConfig::new(resolution).containment_mode(ContainmentMode::IntersectsBoundary);
+        let cells: Vec<CellIndex> = geometry.to_cells(config).collect();
+
+        H3oViewer {
+            cell_groups: Vec::from([cells]),
+            settings: Settings::default(),
+            circles: Vec::new(),
+            bounding_boxes: Vec::new(),
+            tile_grid_zoom: None,
+            cell_values: HashMap::new(),
+            color_scale: ColorScale::default(),
+            group_colors: None,
+        }
+    }
+
+    /// Colors every cell by its associated value, using `color_scale`
+    /// (default [`ColorScale::Viridis`]) over the auto-computed min/max of
+    /// the given values.
+    #[must_use]
+    pub fn for_cell_values(values: impl IntoIterator<Item = (CellIndex, f64)>) -> Self {
+        let cell_values: HashMap<CellIndex, f64> = values.into_iter().collect();
+        let mut cells: Vec<CellIndex> = cell_values.keys().copied().collect();
+        cells.sort();
+
+        H3oViewer {
+            cell_groups: Vec::from([cells]),
+            settings: Settings::default(),
+            circles: Vec::new(),
+            bounding_boxes: Vec::new(),
+            tile_grid_zoom: None,
+            cell_values,
+            color_scale: ColorScale::default(),
+            group_colors: None,
         }
     }
 
+    /// Only has an effect when the viewer was built with
+    /// [`H3oViewer::for_cell_values`].
+    #[must_use]
+    pub fn with_color_scale(mut self, scale: ColorScale) -> Self {
+        self.color_scale = scale;
+        self
+    }
+
     /// Default: off, only works when `render_cells_seperately` is set (default on)
     #[must_use]
     pub fn with_cell_indexes(mut self, set_on: bool) -> Self {
@@ -118,6 +305,30 @@ impl H3oViewer {
         self
     }
 
+    #[must_use]
+    pub fn draw_bounding_box(mut self, bbox: BBox) -> Self {
+        self.bounding_boxes.push(bbox);
+        self
+    }
+
+    /// Overlay the standard Web-Mercator (XYZ/slippy) tiles at `zoom` that
+    /// cover the rendered cells, for comparing H3 hexagons against the
+    /// pyramidal tiles most web basemaps use.
+    #[must_use]
+    pub fn draw_tile_grid(mut self, zoom: u8) -> Self {
+        self.tile_grid_zoom = Some(zoom);
+        self
+    }
+
+    /// Overrides the palette cycled through for distinguishing cell groups.
+    /// Only has an effect when cells are rendered per group: with more than
+    /// one group, or with `render_cells_separately(false)`.
+    #[must_use]
+    pub fn with_group_colors(mut self, colors: Vec<String>) -> Self {
+        self.group_colors = Some(colors);
+        self
+    }
+
     pub fn show_in_browser(self) {
         let mut state = DefaultHasher::new();
         self.hash(&mut state);
@@ -132,23 +343,50 @@ impl H3oViewer {
     }
 
     #[must_use]
-    pub fn generate_html(self) -> String {
+    pub fn generate_html(&self) -> String {
         let geometry = self.cells_to_features();
         let geojson = geometry.to_string();
         let circles = self.generate_circles();
+        let bounding_boxes = self.generate_bounding_boxes();
+        let fit_bounds = self.generate_fit_bounds();
+        let tile_grid = self.generate_tile_grid();
 
         HTML_TEMPLATE
             .replace("{{geojson}}", &geojson)
             .replace("{{circles}}", &circles)
+            .replace("{{bounding_boxes}}", &bounding_boxes)
+            .replace("{{fit_bounds}}", &fit_bounds)
+            .replace("{{tile_grid}}", &tile_grid)
+    }
+
+    /// Returns the rendered GeoJSON `FeatureCollection` as a string, without
+    /// going through a file or browser.
+    #[must_use]
+    pub fn generate_geojson(&self) -> String {
+        self.cells_to_features().to_string()
+    }
+
+    /// Writes the generated HTML viewer to `path`. Unlike
+    /// [`H3oViewer::show_in_browser`], this doesn't depend on
+    /// `CARGO_MANIFEST_DIR` or open a browser, so it works in deployed
+    /// binaries and servers.
+    pub fn save_html(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        fs::write(path, self.generate_html())
+    }
+
+    /// Writes the rendered GeoJSON `FeatureCollection` to `path`.
+    pub fn save_geojson(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        fs::write(path, self.generate_geojson())
     }
 
     fn cells_to_features(&self) -> FeatureCollection {
         if self.settings.separate_cells && self.cell_groups.len() == 1 {
             let mut feature_list = Vec::new();
             let mut edges_seen = Vec::new();
+            let value_domain = self.value_domain();
 
             for cell in &self.cell_groups[0] {
-                let cell_feature = self.cell_to_feature(*cell);
+                let cell_feature = self.cell_to_feature(*cell, value_domain);
                 feature_list.push(cell_feature);
 
                 if self.settings.edge_lengths {
@@ -165,7 +403,8 @@ impl H3oViewer {
         } else {
             self.cell_groups
                 .iter()
-                .map(|cell_group| {
+                .enumerate()
+                .map(|(index, cell_group)| {
                     let mut cell_group = cell_group.clone();
                     cell_group.sort();
                     cell_group.dedup();
@@ -174,8 +413,16 @@ impl H3oViewer {
                         .clone()
                         .to_geojson()
                         .expect("Resolution should be homogenous, and no duplicate cells");
+
+                    let mut properties = JsonObject::new();
+                    properties.insert(
+                        "groupColor".to_string(),
+                        JsonValue::from(self.group_color(index)),
+                    );
+
                     Feature {
                         geometry: Some(geometry),
+                        properties: Some(properties),
                         ..Default::default()
                     }
                 })
@@ -183,11 +430,18 @@ impl H3oViewer {
         }
     }
 
-    fn cell_to_feature(&self, cell: CellIndex) -> Feature {
+    fn group_color(&self, index: usize) -> &str {
+        match &self.group_colors {
+            Some(colors) if !colors.is_empty() => &colors[index % colors.len()],
+            _ => DEFAULT_GROUP_PALETTE[index % DEFAULT_GROUP_PALETTE.len()],
+        }
+    }
+
+    fn cell_to_feature(&self, cell: CellIndex, value_domain: Option<(f64, f64)>) -> Feature {
         let geometry = cell
             .to_geojson()
             .expect("Cannot fail because to_geom cannot fail");
-        let properties = self.get_cell_properties(cell);
+        let properties = self.get_cell_properties(cell, value_domain);
 
         Feature {
             geometry: Some(geometry),
@@ -209,7 +463,7 @@ impl H3oViewer {
         }
     }
 
-    fn get_cell_properties(&self, cell: CellIndex) -> JsonObject {
+    fn get_cell_properties(&self, cell: CellIndex, value_domain: Option<(f64, f64)>) -> JsonObject {
         let mut properties = JsonObject::new();
         let mut val = String::new();
 
@@ -226,9 +480,31 @@ impl H3oViewer {
         }
 
         properties.insert("label".to_string(), JsonValue::from(val));
+
+        if let Some(&value) = self.cell_values.get(&cell) {
+            if let Some((min, max)) = value_domain {
+                let t = if (max - min).abs() > f64::EPSILON {
+                    (value - min) / (max - min)
+                } else {
+                    0.5
+                };
+                properties.insert(
+                    "fillColor".to_string(),
+                    JsonValue::from(self.color_scale.color_at(t)),
+                );
+                properties.insert("fillOpacity".to_string(), JsonValue::from(0.7));
+            }
+        }
+
         properties
     }
 
+    fn value_domain(&self) -> Option<(f64, f64)> {
+        let mut values = self.cell_values.values().copied();
+        let first = values.next()?;
+        Some(values.fold((first, first), |(min, max), v| (min.min(v), max.max(v))))
+    }
+
     fn get_edge_properties(edge: DirectedEdgeIndex) -> JsonObject {
         let mut properties = JsonObject::new();
         let length = if edge.length_m() > 1000.0 {
@@ -254,6 +530,108 @@ impl H3oViewer {
             })
             .collect()
     }
+
+    #[allow(clippy::format_collect)]
+    fn generate_bounding_boxes(&self) -> String {
+        self.bounding_boxes
+            .iter()
+            .map(|bbox| {
+                format!(
+                    "L.rectangle([[{}, {}], [{}, {}]], {{fill: false, color: '#ee0000'}}).addTo(map);\n",
+                    bbox.south, bbox.west, bbox.north, bbox.east
+                )
+            })
+            .collect()
+    }
+
+    /// Union bounding box of every cell in every group, computed from their
+    /// boundary vertices.
+    fn cells_bounding_box(&self) -> Option<BBox> {
+        let mut vertices = self
+            .cell_groups
+            .iter()
+            .flatten()
+            .flat_map(|cell| cell.boundary().into_iter());
+
+        let first = vertices.next()?;
+        let mut bbox = BBox {
+            north: first.lat(),
+            south: first.lat(),
+            east: first.lng(),
+            west: first.lng(),
+        };
+
+        for vertex in vertices {
+            bbox.north = bbox.north.max(vertex.lat());
+            bbox.south = bbox.south.min(vertex.lat());
+            bbox.east = bbox.east.max(vertex.lng());
+            bbox.west = bbox.west.min(vertex.lng());
+        }
+
+        Some(bbox)
+    }
+
+    fn generate_fit_bounds(&self) -> String {
+        self.cells_bounding_box().map_or_else(String::new, |bbox| {
+            format!(
+                "map.fitBounds([[{}, {}], [{}, {}]]);\n",
+                bbox.south, bbox.west, bbox.north, bbox.east
+            )
+        })
+    }
+
+    #[allow(clippy::format_collect)]
+    fn generate_tile_grid(&self) -> String {
+        let (Some(zoom), Some(bbox)) = (self.tile_grid_zoom, self.cells_bounding_box()) else {
+            return String::new();
+        };
+
+        let zoom = zoom.min(MAX_ZOOM);
+        let n = f64::from(1u32 << u32::from(zoom));
+        let (x_min, x_max) = (lon_to_tile_x(bbox.west, n), lon_to_tile_x(bbox.east, n));
+        let (y_min, y_max) = (lat_to_tile_y(bbox.north, n), lat_to_tile_y(bbox.south, n));
+
+        (x_min..=x_max)
+            .flat_map(|x| (y_min..=y_max).map(move |y| (x, y)))
+            .map(|(x, y)| {
+                let (west, east) = (tile_x_to_lon(x, n), tile_x_to_lon(x + 1, n));
+                let (north, south) = (tile_y_to_lat(y, n), tile_y_to_lat(y + 1, n));
+                format!(
+                    "L.rectangle([[{south}, {west}], [{north}, {east}]], {{fill: false, color: '#0000ee'}}).addTo(map);\n"
+                )
+            })
+            .collect()
+    }
+}
+
+const MAX_TILE_LAT: f64 = 85.0511;
+/// Upper bound on `zoom` accepted by [`H3oViewer::draw_tile_grid`]; beyond
+/// the zoom levels web basemaps actually serve, and low enough that
+/// `1u32 << zoom` can't overflow.
+const MAX_ZOOM: u8 = 22;
+
+#[allow(clippy::cast_possible_truncation)]
+fn lon_to_tile_x(lon: f64, n: f64) -> i64 {
+    (((lon + 180.0) / 360.0) * n).floor() as i64
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn lat_to_tile_y(lat: f64, n: f64) -> i64 {
+    let lat_rad = lat.clamp(-MAX_TILE_LAT, MAX_TILE_LAT).to_radians();
+    (((1.0 - lat_rad.tan().asinh() / std::f64::consts::PI) / 2.0) * n).floor() as i64
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn tile_x_to_lon(x: i64, n: f64) -> f64 {
+    (x as f64 / n) * 360.0 - 180.0
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn tile_y_to_lat(y: i64, n: f64) -> f64 {
+    (std::f64::consts::PI * (1.0 - 2.0 * y as f64 / n))
+        .sinh()
+        .atan()
+        .to_degrees()
 }
 
 fn inverse(edge: DirectedEdgeIndex) -> (CellIndex, CellIndex) {
@@ -261,10 +639,9 @@ fn inverse(edge: DirectedEdgeIndex) -> (CellIndex, CellIndex) {
 }
 
 fn open_in_browser(html: &str, filename: &str) -> Result<(), std::io::Error> {
-    let cargo_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let default_path: PathBuf =
-        [&cargo_dir, "target", filename].iter().collect();
-    let second_path: PathBuf = [&cargo_dir, filename].iter().collect();
+    let base_dir = env::var("CARGO_MANIFEST_DIR").map_or_else(|_| env::temp_dir(), PathBuf::from);
+    let default_path = base_dir.join("target").join(filename);
+    let second_path = base_dir.join(filename);
     #[allow(clippy::single_match_else)]
     let path = match fs::write(&default_path, html) {
         Ok(()) => default_path,
@@ -297,4 +674,151 @@ mod tests {
         .draw_circle(center_cell.into(), 200)
         .show_in_browser();
     }
+
+    #[test]
+    fn cells_bounding_box_spans_a_single_cell() {
+        let cell = CellIndex::try_from(0x8a1fb46622dffff).unwrap();
+        let viewer = H3oViewer::for_cells(vec![cell]);
+
+        let bbox = viewer
+            .cells_bounding_box()
+            .expect("a single cell should produce a bbox");
+
+        assert!(bbox.north > bbox.south);
+        assert!(bbox.east > bbox.west);
+    }
+
+    #[test]
+    fn cells_bounding_box_is_none_when_empty() {
+        let viewer = H3oViewer::for_cells(Vec::new());
+
+        assert!(viewer.cells_bounding_box().is_none());
+    }
+
+    #[test]
+    fn for_bounding_box_fills_area_with_cells() {
+        let bbox = BBox {
+            north: 10.0,
+            south: 9.0,
+            east: 10.0,
+            west: 9.0,
+        };
+
+        let viewer = H3oViewer::for_bounding_box(bbox, Resolution::Two);
+
+        assert!(!viewer.cell_groups[0].is_empty());
+        for cell in &viewer.cell_groups[0] {
+            assert_eq!(cell.resolution(), Resolution::Two);
+        }
+    }
+
+    #[test]
+    fn tile_xy_and_lon_lat_round_trip() {
+        let n = f64::from(1u32 << 10);
+        let (lon, lat) = (5.3, 40.7);
+
+        let x = lon_to_tile_x(lon, n);
+        let y = lat_to_tile_y(lat, n);
+        let tile_west = tile_x_to_lon(x, n);
+        let tile_north = tile_y_to_lat(y, n);
+        let tile_east = tile_x_to_lon(x + 1, n);
+        let tile_south = tile_y_to_lat(y + 1, n);
+
+        assert!((tile_west..=tile_east).contains(&lon));
+        assert!((tile_south..=tile_north).contains(&lat));
+    }
+
+    #[test]
+    fn tile_grid_clamps_zoom_instead_of_overflowing() {
+        let cell = CellIndex::try_from(0x8a1fb46622dffff).unwrap();
+        let viewer = H3oViewer::for_cells(vec![cell]).draw_tile_grid(u8::MAX);
+
+        // Should not panic on the shift, and should produce some tiles.
+        assert!(!viewer.generate_tile_grid().is_empty());
+    }
+
+    #[test]
+    fn for_cell_values_orders_cells_deterministically() {
+        let cell_a = CellIndex::try_from(0x8a1fb46622dffff).unwrap();
+        let cell_b = cell_a.grid_disk::<Vec<_>>(1)[1];
+        let values = vec![(cell_a, 1.0), (cell_b, 2.0)];
+
+        let first = H3oViewer::for_cell_values(values.clone());
+        let second = H3oViewer::for_cell_values(values);
+
+        assert_eq!(first.cell_groups, second.cell_groups);
+    }
+
+    #[test]
+    fn hex_rgb_round_trip() {
+        assert_eq!(hex_to_rgb("#ff8000"), (255.0, 128.0, 0.0));
+        assert_eq!(rgb_to_hex(255.0, 128.0, 0.0), "#ff8000");
+    }
+
+    #[test]
+    fn hex_to_rgb_rejects_malformed_input_instead_of_mis_parsing() {
+        // 3-digit CSS shorthand is not expanded, not silently mis-parsed
+        // as the first 2 of its 3 digits.
+        assert_eq!(hex_to_rgb("#abc"), (0.0, 0.0, 0.0));
+        assert_eq!(hex_to_rgb("red"), (0.0, 0.0, 0.0));
+        assert_eq!(hex_to_rgb("#gggggg"), (0.0, 0.0, 0.0));
+        assert_eq!(hex_to_rgb("#ffffffff"), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn color_scale_linear_interpolates_between_endpoints() {
+        let scale = ColorScale::Linear("#000000".to_string(), "#ffffff".to_string());
+
+        assert_eq!(scale.color_at(0.0), "#000000");
+        assert_eq!(scale.color_at(1.0), "#ffffff");
+        assert_eq!(scale.color_at(0.5), "#808080");
+    }
+
+    #[test]
+    fn group_color_cycles_through_default_palette() {
+        let viewer = H3oViewer::for_cell_groups(Vec::<Vec<CellIndex>>::new());
+
+        assert_eq!(viewer.group_color(0), DEFAULT_GROUP_PALETTE[0]);
+        assert_eq!(
+            viewer.group_color(DEFAULT_GROUP_PALETTE.len()),
+            DEFAULT_GROUP_PALETTE[0]
+        );
+    }
+
+    #[test]
+    fn group_color_uses_override_when_set() {
+        let viewer = H3oViewer::for_cell_groups(Vec::<Vec<CellIndex>>::new())
+            .with_group_colors(vec!["#111111".to_string()]);
+
+        assert_eq!(viewer.group_color(0), "#111111");
+        assert_eq!(viewer.group_color(1), "#111111");
+    }
+
+    #[test]
+    fn generate_geojson_produces_a_feature_collection() {
+        let cell = CellIndex::try_from(0x8a1fb46622dffff).unwrap();
+        let viewer = H3oViewer::for_cells(vec![cell]);
+
+        assert!(viewer.generate_geojson().contains("FeatureCollection"));
+    }
+
+    #[test]
+    fn save_html_and_save_geojson_both_borrow() {
+        let cell = CellIndex::try_from(0x8a1fb46622dffff).unwrap();
+        let viewer = H3oViewer::for_cells(vec![cell]);
+
+        let html_path = env::temp_dir().join("h3o-viewer-test-save.html");
+        let geojson_path = env::temp_dir().join("h3o-viewer-test-save.geojson");
+
+        // Both take `&self`, so calling them back-to-back on the same
+        // viewer must compile.
+        viewer.save_html(&html_path).unwrap();
+        viewer.save_geojson(&geojson_path).unwrap();
+
+        assert!(html_path.exists());
+        assert!(geojson_path.exists());
+
+        let _ = fs::remove_file(html_path);
+        let _ = fs::remove_file(geojson_path);
+    }
 }